@@ -0,0 +1,177 @@
+use rand::Rng;
+use rug::integer::Order;
+use rug::Integer;
+use sha256::digest;
+
+use crate::{modular, FrostState};
+
+/// The algebraic structure FROST signs in.
+///
+/// A ciphersuite ties together a prime-order group of `Element`s and its
+/// scalar `Scalar` field, the hash `h` used to derive binding factors, and the
+/// `challenge` hash. `FrostState` is the Schnorr-group (integer) instantiation;
+/// point-based suites such as Ed25519 or Ristretto255 can implement the same
+/// surface without touching the signing logic in [`crate::sign`].
+pub trait Ciphersuite {
+    /// A scalar of the signing field, reduced modulo the group order.
+    type Scalar: Clone + PartialEq;
+    /// An element of the prime-order group.
+    type Element: Clone + PartialEq;
+
+    // --- field arithmetic ---
+
+    fn scalar_add(&self, a: &Self::Scalar, b: &Self::Scalar) -> Self::Scalar;
+    fn scalar_sub(&self, a: &Self::Scalar, b: &Self::Scalar) -> Self::Scalar;
+    fn scalar_mul(&self, a: &Self::Scalar, b: &Self::Scalar) -> Self::Scalar;
+    fn scalar_inv(&self, a: &Self::Scalar) -> Self::Scalar;
+    /// Lift a small non-negative integer (e.g. a participant identifier) into
+    /// the scalar field.
+    fn scalar_from_u32(&self, value: u32) -> Self::Scalar;
+    /// Sample a uniformly random scalar, used for nonces, DKG coefficients and
+    /// batch-verification blinding.
+    fn random_scalar(&self) -> Self::Scalar;
+
+    // --- group arithmetic ---
+
+    /// The group generator.
+    fn generator(&self) -> Self::Element;
+    /// The group identity, i.e. the neutral element of `mul`.
+    fn identity(&self) -> Self::Element;
+    fn mul(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+    fn pow(&self, base: &Self::Element, exponent: &Self::Scalar) -> Self::Element;
+
+    // --- encoding ---
+
+    fn serialize_scalar(&self, scalar: &Self::Scalar) -> Vec<u8>;
+    fn deserialize_scalar(&self, bytes: &[u8]) -> Self::Scalar;
+    fn serialize_element(&self, element: &Self::Element) -> Vec<u8>;
+    fn deserialize_element(&self, bytes: &[u8]) -> Self::Element;
+
+    // --- hashing ---
+
+    /// Generic hash `H`, mapping arbitrary bytes into the scalar field; used to
+    /// derive per-participant binding factors.
+    fn h(&self, input: &[u8]) -> Self::Scalar;
+    /// Challenge hash `c = H(R :: Y :: m)`, mapping into the scalar field.
+    fn challenge(
+        &self,
+        group_commitment: &Self::Element,
+        group_public_key: &Self::Element,
+        message: &str,
+    ) -> Self::Scalar;
+}
+
+impl FrostState {
+    /// The fixed width, in bytes, of a canonical `q`-sized big-endian scalar
+    /// encoding.
+    pub(crate) fn scalar_len(&self) -> usize {
+        (self.q.significant_bits() as usize).div_ceil(8)
+    }
+
+    /// The fixed width, in bytes, of a canonical `p`-sized big-endian element
+    /// encoding.
+    pub(crate) fn element_len(&self) -> usize {
+        (self.p.significant_bits() as usize).div_ceil(8)
+    }
+
+    fn encode_integer(&self, value: &Integer, len: usize) -> Vec<u8> {
+        let mut bytes = vec![0u8; len];
+        let digits = value.to_digits::<u8>(Order::MsfBe);
+        let offset = bytes.len() - digits.len();
+        bytes[offset..].copy_from_slice(&digits);
+        bytes
+    }
+
+    fn decode_integer(&self, bytes: &[u8]) -> Integer {
+        Integer::from_digits(bytes, Order::MsfBe)
+    }
+}
+
+impl Ciphersuite for FrostState {
+    type Scalar = Integer;
+    type Element = Integer;
+
+    fn scalar_add(&self, a: &Integer, b: &Integer) -> Integer {
+        modular::add(a.clone(), b.clone(), &self.q)
+    }
+
+    fn scalar_sub(&self, a: &Integer, b: &Integer) -> Integer {
+        modular::sub(a.clone(), b.clone(), &self.q)
+    }
+
+    fn scalar_mul(&self, a: &Integer, b: &Integer) -> Integer {
+        modular::mul(a.clone(), b.clone(), &self.q)
+    }
+
+    fn scalar_inv(&self, a: &Integer) -> Integer {
+        modular::inv(a, &self.q)
+    }
+
+    fn scalar_from_u32(&self, value: u32) -> Integer {
+        Integer::from(value).modulo(&self.q)
+    }
+
+    fn random_scalar(&self) -> Integer {
+        // Rejection sampling: mask to `q`'s bit length and retry until the
+        // candidate lies in `[0, q)`, so no value is favoured by a reduction.
+        let bits = self.q.significant_bits();
+        let mut rng = rand::thread_rng();
+        loop {
+            let mut bytes = vec![0u8; self.scalar_len()];
+            rng.fill(bytes.as_mut_slice());
+            let candidate = Integer::from_digits(&bytes, Order::MsfBe).keep_bits(bits);
+            if candidate < self.q {
+                return candidate;
+            }
+        }
+    }
+
+    fn generator(&self) -> Integer {
+        self.generator.clone()
+    }
+
+    fn identity(&self) -> Integer {
+        Integer::from(1)
+    }
+
+    fn mul(&self, a: &Integer, b: &Integer) -> Integer {
+        modular::mul(a.clone(), b.clone(), &self.p)
+    }
+
+    fn pow(&self, base: &Integer, exponent: &Integer) -> Integer {
+        modular::pow(base, exponent, &self.p)
+    }
+
+    fn serialize_scalar(&self, scalar: &Integer) -> Vec<u8> {
+        self.encode_integer(scalar, self.scalar_len())
+    }
+
+    fn deserialize_scalar(&self, bytes: &[u8]) -> Integer {
+        self.decode_integer(bytes)
+    }
+
+    fn serialize_element(&self, element: &Integer) -> Vec<u8> {
+        self.encode_integer(element, self.element_len())
+    }
+
+    fn deserialize_element(&self, bytes: &[u8]) -> Integer {
+        self.decode_integer(bytes)
+    }
+
+    fn h(&self, input: &[u8]) -> Integer {
+        Integer::from_str_radix(digest(input).as_str(), 16)
+            .unwrap()
+            .modulo(&self.q)
+    }
+
+    fn challenge(
+        &self,
+        group_commitment: &Integer,
+        group_public_key: &Integer,
+        message: &str,
+    ) -> Integer {
+        self.h(
+            format!("{}::::{}::::{}", group_commitment, group_public_key, message).as_bytes(),
+        )
+    }
+}
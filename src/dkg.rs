@@ -0,0 +1,406 @@
+//! Dealerless distributed key generation (a two-round Pedersen DKG).
+//!
+//! Instead of trusting a dealer to hand out `public_share`/`private_key`, the
+//! participants jointly generate the group key. In round 1 each participant
+//! samples a secret polynomial, publishes commitments to its coefficients and a
+//! Schnorr proof of knowledge of its constant term; in round 2 each participant
+//! sends every other its polynomial evaluation and everyone verifies the
+//! received shares against the published commitments. The long-term secret of
+//! participant `j` is `Σ_i f_i(j)`, its `public_share` is `g^{secret}`, and the
+//! group public key is `∏_i C_{i,0}`.
+//!
+//! [`finalize`] ties it together: it rejects any peer whose proof or share
+//! fails to verify and only then aggregates, and [`to_public_commitment`]
+//! lifts the result straight into the [`crate::sign`] flow.
+
+use crate::ciphersuite::Ciphersuite;
+use crate::sign::PublicCommitment;
+
+/// A secret polynomial `f_i(x) = Σ_k a_{i,k} x^k` of degree `t-1`, kept private
+/// by the participant that sampled it.
+pub struct SecretPolynomial<C: Ciphersuite> {
+    pub coefficients: Vec<C::Scalar>,
+}
+
+impl<C: Ciphersuite> SecretPolynomial<C> {
+    /// Evaluate the polynomial at `x` via Horner's method.
+    pub fn evaluate(&self, cs: &C, x: &C::Scalar) -> C::Scalar {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(cs.scalar_from_u32(0), |acc, coefficient| {
+                cs.scalar_add(&cs.scalar_mul(&acc, x), coefficient)
+            })
+    }
+}
+
+/// A Schnorr proof of knowledge of a polynomial's constant term `a_{i,0}`.
+pub struct SchnorrProof<C: Ciphersuite> {
+    pub commitment: C::Element,
+    pub response: C::Scalar,
+}
+
+/// The private output of round 1: the sampled polynomial, retained so the
+/// participant can later hand out its shares.
+pub struct Round1Secret<C: Ciphersuite> {
+    pub participant_id: C::Scalar,
+    pub polynomial: SecretPolynomial<C>,
+}
+
+/// The public output of round 1, broadcast to every participant: the
+/// coefficient commitments `C_{i,k} = g^{a_{i,k}}` and a proof of knowledge of
+/// `a_{i,0}`.
+pub struct Round1Package<C: Ciphersuite> {
+    pub participant_id: C::Scalar,
+    pub commitments: Vec<C::Element>,
+    pub proof: SchnorrProof<C>,
+}
+
+/// The validated long-term key material a participant ends up with, ready to
+/// feed into the signing flow via [`to_public_commitment`].
+pub struct KeyGenOutput<C: Ciphersuite> {
+    pub participant_id: C::Scalar,
+    pub secret: C::Scalar,
+    pub public_share: C::Element,
+    pub group_public_key: C::Element,
+}
+
+/// Why a participant's round-1 package or round-2 share was rejected. The
+/// carried scalar is the offending participant's identifier.
+pub enum DkgError<C: Ciphersuite> {
+    InvalidProof(C::Scalar),
+    MissingPackage(C::Scalar),
+    InvalidShare(C::Scalar),
+    /// A package was published in round 1 but no share from that participant
+    /// showed up in round 2.
+    MissingShare(C::Scalar),
+    /// The same participant's share was received more than once.
+    DuplicateShare(C::Scalar),
+}
+
+impl<C: Ciphersuite> core::fmt::Debug for DkgError<C>
+where
+    C::Scalar: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DkgError::InvalidProof(id) => write!(f, "InvalidProof({:?})", id),
+            DkgError::MissingPackage(id) => write!(f, "MissingPackage({:?})", id),
+            DkgError::InvalidShare(id) => write!(f, "InvalidShare({:?})", id),
+            DkgError::MissingShare(id) => write!(f, "MissingShare({:?})", id),
+            DkgError::DuplicateShare(id) => write!(f, "DuplicateShare({:?})", id),
+        }
+    }
+}
+
+/// `base^exp` in the scalar field, used to raise an identifier to an integer
+/// power `x_j^k`.
+fn scalar_pow<C: Ciphersuite>(cs: &C, base: &C::Scalar, exp: usize) -> C::Scalar {
+    (0..exp).fold(cs.scalar_from_u32(1), |acc, _| cs.scalar_mul(&acc, base))
+}
+
+/// Fiat-Shamir challenge binding the prover's identity, committed constant term
+/// and Schnorr commitment.
+fn proof_challenge<C: Ciphersuite>(
+    cs: &C,
+    participant_id: &C::Scalar,
+    c0: &C::Element,
+    r: &C::Element,
+) -> C::Scalar {
+    let mut input = cs.serialize_scalar(participant_id);
+    input.extend_from_slice(&cs.serialize_element(c0));
+    input.extend_from_slice(&cs.serialize_element(r));
+    cs.h(&input)
+}
+
+/// Round 1: sample a degree `threshold - 1` polynomial, commit to its
+/// coefficients and prove knowledge of the constant term. The returned
+/// [`Round1Secret`] must be kept private; the [`Round1Package`] is broadcast.
+pub fn round1<C: Ciphersuite>(
+    cs: &C,
+    participant_id: C::Scalar,
+    threshold: u32,
+) -> (Round1Secret<C>, Round1Package<C>) {
+    let coefficients: Vec<C::Scalar> = (0..threshold).map(|_| cs.random_scalar()).collect();
+    let commitments: Vec<C::Element> = coefficients
+        .iter()
+        .map(|a| cs.pow(&cs.generator(), a))
+        .collect();
+
+    let k = cs.random_scalar();
+    let commitment = cs.pow(&cs.generator(), &k);
+    let challenge = proof_challenge(cs, &participant_id, &commitments[0], &commitment);
+    let response = cs.scalar_add(&k, &cs.scalar_mul(&coefficients[0], &challenge));
+
+    let package = Round1Package {
+        participant_id: participant_id.clone(),
+        commitments,
+        proof: SchnorrProof {
+            commitment,
+            response,
+        },
+    };
+    let secret = Round1Secret {
+        participant_id,
+        polynomial: SecretPolynomial { coefficients },
+    };
+    (secret, package)
+}
+
+/// Verify a round-1 package's proof of knowledge of `a_{i,0}`: check that
+/// `g^z == R * C_{i,0}^c`.
+pub fn verify_round1_package<C: Ciphersuite>(cs: &C, package: &Round1Package<C>) -> bool {
+    let challenge = proof_challenge(
+        cs,
+        &package.participant_id,
+        &package.commitments[0],
+        &package.proof.commitment,
+    );
+    let gz = cs.pow(&cs.generator(), &package.proof.response);
+    let expected = cs.mul(
+        &package.proof.commitment,
+        &cs.pow(&package.commitments[0], &challenge),
+    );
+    gz == expected
+}
+
+/// Round 2: the share `f_i(j)` that participant `i` sends to participant `j`.
+pub fn round2_share<C: Ciphersuite>(
+    cs: &C,
+    secret: &Round1Secret<C>,
+    recipient_id: &C::Scalar,
+) -> C::Scalar {
+    secret.polynomial.evaluate(cs, recipient_id)
+}
+
+/// Verify a share received from participant `i` against `i`'s published
+/// coefficient commitments: `g^{f_i(j)} == ∏_k C_{i,k}^{j^k}`.
+pub fn verify_share<C: Ciphersuite>(
+    cs: &C,
+    package: &Round1Package<C>,
+    recipient_id: &C::Scalar,
+    share: &C::Scalar,
+) -> bool {
+    let gs = cs.pow(&cs.generator(), share);
+    let expected = package
+        .commitments
+        .iter()
+        .enumerate()
+        .fold(cs.identity(), |acc, (k, commitment)| {
+            let exponent = scalar_pow(cs, recipient_id, k);
+            cs.mul(&acc, &cs.pow(commitment, &exponent))
+        });
+    gs == expected
+}
+
+/// Combine the verified shares a participant received from every peer into its
+/// long-term secret `Σ_i f_i(j)`.
+pub fn aggregate_secret<C: Ciphersuite>(cs: &C, received_shares: &[C::Scalar]) -> C::Scalar {
+    received_shares
+        .iter()
+        .fold(cs.scalar_from_u32(0), |acc, share| {
+            cs.scalar_add(&acc, share)
+        })
+}
+
+/// The `public_share` `g^{secret}` matching an aggregated long-term secret.
+pub fn public_share<C: Ciphersuite>(cs: &C, secret: &C::Scalar) -> C::Element {
+    cs.pow(&cs.generator(), secret)
+}
+
+/// The group public key `∏_i C_{i,0}`, assembled from every participant's
+/// round-1 package.
+pub fn group_public_key<C: Ciphersuite>(cs: &C, packages: &[Round1Package<C>]) -> C::Element {
+    packages.iter().fold(cs.identity(), |acc, package| {
+        cs.mul(&acc, &package.commitments[0])
+    })
+}
+
+/// Validate and finalize this participant's key material: verify every peer's
+/// proof of knowledge, verify every share it received against the matching
+/// published commitments, and require exactly one verified share per
+/// published package — rejecting (without aggregating) on the first peer
+/// whose proof or share fails, on a withheld share, or on a duplicate one.
+/// Only once everything checks out are the shares summed into the long-term
+/// secret and the group key assembled.
+pub fn finalize<C: Ciphersuite>(
+    cs: &C,
+    participant_id: C::Scalar,
+    packages: &[Round1Package<C>],
+    received_shares: &[(C::Scalar, C::Scalar)],
+) -> Result<KeyGenOutput<C>, DkgError<C>> {
+    for package in packages {
+        if !verify_round1_package(cs, package) {
+            return Err(DkgError::InvalidProof(package.participant_id.clone()));
+        }
+    }
+
+    let mut shares = Vec::with_capacity(received_shares.len());
+    let mut seen_ids: Vec<C::Scalar> = Vec::with_capacity(received_shares.len());
+    for (from_id, share) in received_shares {
+        if seen_ids.contains(from_id) {
+            return Err(DkgError::DuplicateShare(from_id.clone()));
+        }
+        let package = packages
+            .iter()
+            .find(|p| p.participant_id == *from_id)
+            .ok_or_else(|| DkgError::MissingPackage(from_id.clone()))?;
+        if !verify_share(cs, package, &participant_id, share) {
+            return Err(DkgError::InvalidShare(from_id.clone()));
+        }
+        seen_ids.push(from_id.clone());
+        shares.push(share.clone());
+    }
+
+    // `group_public_key` below is assembled from every package, so the
+    // aggregated secret must likewise account for every one of them — a
+    // share withheld by a peer would otherwise silently desynchronize
+    // `secret`/`public_share` from the key `group_public_key` implies.
+    if let Some(missing) = packages
+        .iter()
+        .find(|package| !seen_ids.contains(&package.participant_id))
+    {
+        return Err(DkgError::MissingShare(missing.participant_id.clone()));
+    }
+
+    let secret = aggregate_secret(cs, &shares);
+    let public_share = public_share(cs, &secret);
+    let group_public_key = group_public_key(cs, packages);
+    Ok(KeyGenOutput {
+        participant_id,
+        secret,
+        public_share,
+        group_public_key,
+    })
+}
+
+/// Lift finalized DKG output into a [`PublicCommitment`] for the signing round,
+/// pairing the participant's identity and `public_share` with its per-round
+/// nonce commitments `di`/`ei`.
+pub fn to_public_commitment<C: Ciphersuite>(
+    output: &KeyGenOutput<C>,
+    di: C::Element,
+    ei: C::Element,
+) -> PublicCommitment<C> {
+    PublicCommitment::new(
+        output.participant_id.clone(),
+        di,
+        ei,
+        output.public_share.clone(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FrostState;
+    use rug::Integer;
+
+    // A safe-prime pair (p = 2q + 1 = 2_000_303); `25` has order
+    // `q = 1_000_151` modulo `p`.
+    fn state() -> FrostState {
+        FrostState::new(
+            Integer::from(2_000_303),
+            Integer::from(1_000_151),
+            Integer::from(25),
+        )
+    }
+
+    // Build a round-1 package by hand so the test is deterministic. A zero
+    // constant term keeps the expected shares easy to compute by hand; the
+    // proof of knowledge is still built and checked as in `round1`.
+    fn fixture_package(state: &FrostState, id: u32, tail: &[u32], k: u32) -> Round1Package<FrostState> {
+        let mut coefficients = vec![Integer::from(0)];
+        coefficients.extend(tail.iter().map(|c| Integer::from(*c)));
+        let commitments: Vec<Integer> = coefficients
+            .iter()
+            .map(|a| state.pow(&state.generator(), a))
+            .collect();
+        let commitment = state.pow(&state.generator(), &Integer::from(k));
+        let challenge = proof_challenge(state, &Integer::from(id), &commitments[0], &commitment);
+        let response = state.scalar_add(
+            &Integer::from(k),
+            &state.scalar_mul(&coefficients[0], &challenge),
+        );
+        Round1Package {
+            participant_id: Integer::from(id),
+            commitments,
+            proof: SchnorrProof {
+                commitment,
+                response,
+            },
+        }
+    }
+
+    #[test]
+    fn verify_share_accepts_valid_and_rejects_tampered() {
+        let state = state();
+        let package = fixture_package(&state, 1, &[3, 2], 9);
+        let recipient = Integer::from(2);
+        // f(2) = 0 + 3*2 + 2*4 = 14.
+        let share = Integer::from(14);
+        assert!(verify_share(&state, &package, &recipient, &share));
+        assert!(!verify_share(&state, &package, &recipient, &Integer::from(15)));
+    }
+
+    #[test]
+    fn finalize_rejects_before_aggregating() {
+        let state = state();
+        let package = fixture_package(&state, 1, &[3, 2], 9);
+        let me = Integer::from(2);
+
+        // Happy path: valid proof and share finalize into usable key material.
+        let output = finalize(&state, me.clone(), &[package], &[(Integer::from(1), Integer::from(14))])
+            .expect("valid DKG should finalize");
+        assert_eq!(output.secret, Integer::from(14));
+        assert_eq!(output.public_share, state.pow(&state.generator(), &Integer::from(14)));
+
+        // A tampered share is rejected rather than silently summed.
+        let package = fixture_package(&state, 1, &[3, 2], 9);
+        let err = finalize(&state, me, &[package], &[(Integer::from(1), Integer::from(99))])
+            .expect_err("tampered share must be rejected");
+        assert!(matches!(err, DkgError::InvalidShare(_)));
+    }
+
+    #[test]
+    fn finalize_rejects_missing_and_duplicate_shares() {
+        let state = state();
+        let me = Integer::from(4);
+        let packages = vec![
+            fixture_package(&state, 1, &[3, 2], 9),
+            fixture_package(&state, 2, &[5, 1], 7),
+            fixture_package(&state, 3, &[2, 6], 3),
+        ];
+        // f_1(4) = 0 + 3*4 + 2*16 = 44.
+        // f_2(4) = 0 + 5*4 + 1*16 = 36.
+        // f_3(4) = 0 + 2*4 + 6*16 = 104.
+        let complete_shares = vec![
+            (Integer::from(1), Integer::from(44)),
+            (Integer::from(2), Integer::from(36)),
+            (Integer::from(3), Integer::from(104)),
+        ];
+
+        // Happy path: one verified share per published package finalizes.
+        let output = finalize(&state, me.clone(), &packages, &complete_shares)
+            .expect("a complete set of valid shares should finalize");
+        assert_eq!(output.secret, Integer::from(44 + 36 + 104));
+
+        // Participant 3 withheld its share: rejected rather than silently
+        // aggregating the two that did arrive.
+        let incomplete = &complete_shares[..2];
+        let err = finalize(&state, me.clone(), &packages, incomplete)
+            .expect_err("a share withheld by a peer must be rejected");
+        assert!(matches!(err, DkgError::MissingShare(_)));
+
+        // Participant 1's share counted twice instead of participant 3's:
+        // rejected rather than double-counting it into the secret.
+        let duplicated = [
+            complete_shares[0].clone(),
+            complete_shares[1].clone(),
+            complete_shares[0].clone(),
+        ];
+        let err = finalize(&state, me, &packages, &duplicated)
+            .expect_err("a duplicated share must be rejected");
+        assert!(matches!(err, DkgError::DuplicateShare(_)));
+    }
+}
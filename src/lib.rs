@@ -0,0 +1,36 @@
+use rug::Integer;
+
+pub mod ciphersuite;
+pub mod dkg;
+pub mod modular;
+pub mod sign;
+
+/// Public parameters of the FROST integer-group instantiation: the Schnorr
+/// group is the order-`q` subgroup of `Z_p^*` for a safe prime `p = 2q + 1`,
+/// generated by `generator`. Group operations (`mul`/`pow`) are carried out
+/// modulo `p`; scalar arithmetic (nonces, keys, Lagrange coefficients, ...) is
+/// carried out modulo `q`. Using a single modulus for both, as `Z_q^*` has
+/// order `q - 1` rather than `q`, silently breaks the Schnorr identity `g^z ==
+/// r * Y^c` for essentially any scalar sum that wraps around `q`.
+pub struct FrostState {
+    pub p: Integer,
+    pub q: Integer,
+    pub generator: Integer,
+}
+
+impl FrostState {
+    /// `generator` must have order `q` modulo `p`, i.e. `p` is prime,
+    /// `q` divides `p - 1`, and `generator^q ≡ 1 (mod p)` with `generator !=
+    /// 1`. Debug builds check `generator != 1` and `generator^q ≡ 1 (mod p)`;
+    /// the primality of `p` and `q` is not checked, and release builds trust
+    /// the caller.
+    pub fn new(p: Integer, q: Integer, generator: Integer) -> Self {
+        debug_assert_ne!(generator, Integer::from(1), "generator must not be the identity");
+        debug_assert_eq!(
+            modular::pow(&generator, &q, &p),
+            Integer::from(1),
+            "generator must have order q modulo p"
+        );
+        Self { p, q, generator }
+    }
+}
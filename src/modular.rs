@@ -0,0 +1,35 @@
+use rug::Integer;
+
+/// `a + b mod modulus`, reduced into `[0, modulus)`.
+pub fn add(a: Integer, b: Integer, modulus: &Integer) -> Integer {
+    (a + b).modulo(modulus)
+}
+
+/// `a - b mod modulus`, reduced into `[0, modulus)`.
+pub fn sub(a: Integer, b: Integer, modulus: &Integer) -> Integer {
+    (a - b).modulo(modulus)
+}
+
+/// `a * b mod modulus`, reduced into `[0, modulus)`.
+pub fn mul(a: Integer, b: Integer, modulus: &Integer) -> Integer {
+    (a * b).modulo(modulus)
+}
+
+/// The modular inverse of `a` mod `modulus`.
+pub fn inv(a: &Integer, modulus: &Integer) -> Integer {
+    a.clone()
+        .invert(modulus)
+        .expect("element is not invertible modulo the group order")
+}
+
+/// `a / b mod modulus`, i.e. `a * b^{-1} mod modulus`.
+pub fn div(a: Integer, b: Integer, modulus: &Integer) -> Integer {
+    mul(a, inv(&b, modulus), modulus)
+}
+
+/// `base^exponent mod modulus`.
+pub fn pow(base: &Integer, exponent: &Integer, modulus: &Integer) -> Integer {
+    base.clone()
+        .pow_mod(exponent, modulus)
+        .expect("failed to exponentiate modulo the given modulus")
+}
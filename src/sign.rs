@@ -1,18 +1,22 @@
-use rand::Rng;
 use rug::Integer;
-use sha256::digest;
 
-use crate::{modular, FrostState};
+use crate::ciphersuite::Ciphersuite;
+use crate::FrostState;
 
-pub struct PublicCommitment {
-    pub participant_id: Integer,
-    pub di: Integer,
-    pub ei: Integer,
-    pub public_share: Integer,
+pub struct PublicCommitment<C: Ciphersuite> {
+    pub participant_id: C::Scalar,
+    pub di: C::Element,
+    pub ei: C::Element,
+    pub public_share: C::Element,
 }
 
-impl PublicCommitment {
-    pub fn new(participant_id: Integer, di: Integer, ei: Integer, public_share: Integer) -> Self {
+impl<C: Ciphersuite> PublicCommitment<C> {
+    pub fn new(
+        participant_id: C::Scalar,
+        di: C::Element,
+        ei: C::Element,
+        public_share: C::Element,
+    ) -> Self {
         Self {
             participant_id,
             di,
@@ -20,146 +24,634 @@ impl PublicCommitment {
             public_share,
         }
     }
+}
 
+impl PublicCommitment<FrostState> {
     pub fn to_string(&self) -> String {
-        format!("{}::{}::{}", self.participant_id, self.di, self.ei)
+        format!(
+            "{}::{}::{}::{}",
+            self.participant_id, self.di, self.ei, self.public_share
+        )
+    }
+
+    /// Canonical wire encoding: the four fields, each under a `u32` length
+    /// prefix; `participant_id` is a `q`-sized big-endian integer and `di`,
+    /// `ei` and `public_share` are `p`-sized big-endian integers.
+    pub fn to_bytes(&self, state: &FrostState) -> Vec<u8> {
+        let mut out = Vec::new();
+        push_field(&mut out, &state.serialize_scalar(&self.participant_id));
+        push_field(&mut out, &state.serialize_element(&self.di));
+        push_field(&mut out, &state.serialize_element(&self.ei));
+        push_field(&mut out, &state.serialize_element(&self.public_share));
+        out
+    }
+
+    /// Reconstruct a commitment from [`PublicCommitment::to_bytes`]; returns
+    /// `None` on truncated or malformed framing.
+    pub fn from_bytes(state: &FrostState, bytes: &[u8]) -> Option<Self> {
+        let mut offset = 0;
+        let participant_id = state.deserialize_scalar(read_field(bytes, &mut offset)?);
+        let di = state.deserialize_element(read_field(bytes, &mut offset)?);
+        let ei = state.deserialize_element(read_field(bytes, &mut offset)?);
+        let public_share = state.deserialize_element(read_field(bytes, &mut offset)?);
+        Some(Self::new(participant_id, di, ei, public_share))
+    }
+}
+
+/// Append a `u32`-big-endian length prefix followed by `field` to `out`.
+fn push_field(out: &mut Vec<u8>, field: &[u8]) {
+    out.extend_from_slice(&(field.len() as u32).to_be_bytes());
+    out.extend_from_slice(field);
+}
+
+/// Read one length-prefixed field from `bytes`, advancing `offset`. Returns
+/// `None` if the buffer is too short for the prefix or the announced length.
+fn read_field<'a>(bytes: &'a [u8], offset: &mut usize) -> Option<&'a [u8]> {
+    let len = u32::from_be_bytes(bytes.get(*offset..*offset + 4)?.try_into().ok()?) as usize;
+    *offset += 4;
+    let field = bytes.get(*offset..*offset + len)?;
+    *offset += len;
+    Some(field)
+}
+
+/// A per-participant signing response, paired with the identifier it came
+/// from so it can be routed over the wire. Generic over the ciphersuite, like
+/// [`Signature`] and [`PublicCommitment`], so it serves the integer group,
+/// Ed25519, Ristretto255, etc.
+pub struct Response<C: Ciphersuite> {
+    pub participant_id: C::Scalar,
+    pub value: C::Scalar,
+}
+
+impl<C: Ciphersuite> Response<C> {
+    pub fn new(participant_id: C::Scalar, value: C::Scalar) -> Self {
+        Self {
+            participant_id,
+            value,
+        }
+    }
+
+    /// Canonical wire encoding: `participant_id` then `value`, each serialized
+    /// with the ciphersuite encoding under a `u32` length prefix.
+    pub fn to_bytes(&self, cs: &C) -> Vec<u8> {
+        let mut out = Vec::new();
+        push_field(&mut out, &cs.serialize_scalar(&self.participant_id));
+        push_field(&mut out, &cs.serialize_scalar(&self.value));
+        out
+    }
+
+    /// Reconstruct a response from [`Response::to_bytes`]; returns `None` on
+    /// truncated or malformed framing.
+    pub fn from_bytes(cs: &C, bytes: &[u8]) -> Option<Self> {
+        let mut offset = 0;
+        let participant_id = cs.deserialize_scalar(read_field(bytes, &mut offset)?);
+        let value = cs.deserialize_scalar(read_field(bytes, &mut offset)?);
+        Some(Self::new(participant_id, value))
+    }
+}
+
+/// Canonically encode the whole ordered commitment set as the CFRG FROST
+/// `encode_group_commitment_list`: for each participant, a length-prefixed
+/// `(id, di, ei)` triple serialized with the ciphersuite encodings. The leading
+/// count and per-field length framing make the byte string unambiguous so two
+/// implementations derive identical binding factors.
+fn encode_commitments<C: Ciphersuite>(
+    cs: &C,
+    participants_commitments: &[PublicCommitment<C>],
+) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(&(participants_commitments.len() as u32).to_be_bytes());
+    for pc in participants_commitments {
+        push_field(&mut out, &cs.serialize_scalar(&pc.participant_id));
+        push_field(&mut out, &cs.serialize_element(&pc.di));
+        push_field(&mut out, &cs.serialize_element(&pc.ei));
     }
+    out
 }
 
-pub fn compute_binding_value(
-    state: &FrostState,
-    participant_commitment: &PublicCommitment,
+/// The per-participant binding factor `rho_i = H(identifier :: H(message) ::
+/// encode_commitments(all_commitments))`, reduced into the scalar field. Binding
+/// every share to the entire ordered commitment set is what makes the resulting
+/// signature interoperable with other CFRG FROST implementations.
+pub fn compute_binding_value<C: Ciphersuite>(
+    cs: &C,
+    participant_id: &C::Scalar,
+    participants_commitments: &[PublicCommitment<C>],
     message: &str,
-) -> Integer {
-    Integer::from_str_radix(
-        digest(format!(
-            "{}::::{}::::{}",
-            participant_commitment.participant_id,
-            message,
-            participant_commitment.to_string()
-        ))
-        .as_str(),
-        16,
-    )
-    .unwrap()
-    .modulo(&state.q)
-}
-
-pub fn compute_group_commitment_and_challenge(
-    state: &FrostState,
-    participants_commitments: &[PublicCommitment],
+) -> C::Scalar {
+    let mut input = cs.serialize_scalar(participant_id);
+    input.extend_from_slice(&cs.serialize_scalar(&cs.h(message.as_bytes())));
+    input.extend_from_slice(&encode_commitments(cs, participants_commitments));
+    cs.h(&input)
+}
+
+pub fn compute_group_commitment_and_challenge<C: Ciphersuite>(
+    cs: &C,
+    participants_commitments: &[PublicCommitment<C>],
     message: &str,
-    group_public_key: Integer,
-) -> (Integer, Integer) {
-    let group_commitment = participants_commitments
-        .iter()
-        .fold(Integer::from(1), |acc, pc| {
-            let binding_value = compute_binding_value(&state, &pc, &message);
-            modular::mul(
-                modular::mul(acc.clone(), pc.di.clone(), &state.q),
-                modular::pow(&pc.ei, &binding_value, &state.q),
-                &state.q,
-            )
-        });
-    let challenge = Integer::from_str_radix(
-        digest(format!(
-            "{}::::{}::::{}",
-            group_commitment, group_public_key, message
-        ))
-        .as_str(),
-        16,
-    )
-    .unwrap()
-    .modulo(&state.q);
+    group_public_key: C::Element,
+) -> (C::Element, C::Scalar) {
+    let group_commitment =
+        participants_commitments
+            .iter()
+            .fold(cs.identity(), |acc, pc| {
+                let binding_value =
+                    compute_binding_value(cs, &pc.participant_id, participants_commitments, message);
+                cs.mul(&cs.mul(&acc, &pc.di), &cs.pow(&pc.ei, &binding_value))
+            });
+    let challenge = cs.challenge(&group_commitment, &group_public_key, message);
     (group_commitment, challenge)
 }
 
-pub fn lagrange_coefficient(
-    state: &FrostState,
-    participant_id: &Integer,
-    number_of_participants: u32,
-) -> Integer {
-    (0..(number_of_participants)).fold(Integer::from(1), |acc, j| {
-        let j = Integer::from(j);
-        modular::mul(
-            acc.clone(),
-            modular::div(
-                j.clone(),
-                modular::sub(j, participant_id.clone(), &state.q),
-                &state.q,
-            ),
-            &state.q,
-        )
-    })
+/// The Lagrange coefficient `λ_i = ∏_{j∈S, j≠i} x_j / (x_j - x_i) mod q` that
+/// interpolates participant `participant_id`'s share to the secret at zero,
+/// over the explicit signing set `S` of participating identifiers. This is
+/// what makes any `t`-of-`n` subset — not just the sequential set `0..n` —
+/// produce a valid aggregate.
+pub fn lagrange_coefficient<C: Ciphersuite>(
+    cs: &C,
+    participant_id: &C::Scalar,
+    signing_set: &[C::Scalar],
+) -> C::Scalar {
+    signing_set
+        .iter()
+        .filter(|x_j| *x_j != participant_id)
+        .fold(cs.scalar_from_u32(1), |acc, x_j| {
+            cs.scalar_mul(
+                &acc,
+                &cs.scalar_mul(x_j, &cs.scalar_inv(&cs.scalar_sub(x_j, participant_id))),
+            )
+        })
 }
 
-pub fn compute_own_response(
-    state: &FrostState,
-    participant_commitment: &PublicCommitment,
-    private_key: &Integer,
-    nonces: &(Integer, Integer),
-    lagrange_coefficient: &Integer,
-    challenge: &Integer,
+pub fn compute_own_response<C: Ciphersuite>(
+    cs: &C,
+    participant_commitment: &PublicCommitment<C>,
+    participants_commitments: &[PublicCommitment<C>],
+    private_key: &C::Scalar,
+    nonces: &(C::Scalar, C::Scalar),
+    signing_set: &[C::Scalar],
+    challenge: &C::Scalar,
     message: &str,
-) -> Integer {
-    let binding_value = compute_binding_value(&state, &participant_commitment, &message);
+) -> Response<C> {
+    let binding_value = compute_binding_value(
+        cs,
+        &participant_commitment.participant_id,
+        participants_commitments,
+        message,
+    );
+    let lambda = lagrange_coefficient(cs, &participant_commitment.participant_id, signing_set);
     let (di, ei) = nonces;
-    modular::add(
-        di.clone(),
-        modular::add(
-            modular::mul(ei.clone(), binding_value, &state.q),
-            modular::mul(
-                modular::mul(lagrange_coefficient.clone(), private_key.clone(), &state.q),
-                challenge.clone(),
-                &state.q,
-            ),
-            &state.q,
+    let value = cs.scalar_add(
+        di,
+        &cs.scalar_add(
+            &cs.scalar_mul(ei, &binding_value),
+            &cs.scalar_mul(&cs.scalar_mul(&lambda, private_key), challenge),
         ),
-        &state.q,
-    )
+    );
+    Response::new(participant_commitment.participant_id.clone(), value)
 }
 
-pub fn verify_participants(
-    state: &FrostState,
-    participants_commitments: &[PublicCommitment],
+/// Verify an aggregate signing round against the participants' public
+/// commitments. Each entry contributes a term `di * ei^{rho_i} *
+/// public_share_i^{c·λ_i}`; the *product* of those terms over the signing set
+/// must equal `g^{z}` for the aggregate response `z`. Comparing the product
+/// once — rather than one shared response against every individual term — is
+/// what lets any `t`-of-`n` subset produce a verifiable aggregate.
+pub fn verify_participants<C: Ciphersuite>(
+    cs: &C,
+    participants_commitments: &[PublicCommitment<C>],
     message: &str,
-    own_response: &Integer,
-    challenge: &Integer,
-    number_of_participants: u32,
+    aggregate_response: &C::Scalar,
+    challenge: &C::Scalar,
+    signing_set: &[C::Scalar],
 ) -> bool {
-    let gz = modular::pow(&state.generator, &own_response, &state.q);
-    participants_commitments.iter().fold(true, |acc, pc| {
-        let binding_value = compute_binding_value(&state, &pc, &message);
-        let ri = modular::mul(
-            pc.di.clone(),
-            modular::pow(&pc.ei, &binding_value, &state.q),
-            &state.q,
-        );
-        let to_validate = modular::mul(
-            ri,
-            modular::pow(
-                &pc.public_share,
-                &modular::mul(
-                    challenge.clone(),
-                    lagrange_coefficient(&state, &pc.participant_id, number_of_participants),
-                    &state.q,
+    let gz = cs.pow(&cs.generator(), aggregate_response);
+    let expected = participants_commitments
+        .iter()
+        .fold(cs.identity(), |acc, pc| {
+            let binding_value =
+                compute_binding_value(cs, &pc.participant_id, participants_commitments, message);
+            let ri = cs.mul(&pc.di, &cs.pow(&pc.ei, &binding_value));
+            let term = cs.mul(
+                &ri,
+                &cs.pow(
+                    &pc.public_share,
+                    &cs.scalar_mul(
+                        challenge,
+                        &lagrange_coefficient(cs, &pc.participant_id, signing_set),
+                    ),
                 ),
-                &state.q,
-            ),
-            &state.q,
-        );
-        assert_eq!(to_validate, gz, "Failed to validate the participants.");
-        acc && (to_validate == gz)
-    })
+            );
+            cs.mul(&acc, &term)
+        });
+    gz == expected
 }
 
-pub fn compute_aggregate_response(
-    state: &FrostState,
-    participants_responses: &[Integer],
-) -> Integer {
+/// Sum the per-participant [`Response`] values into the aggregate response
+/// `z = Σ_i z_i`.
+pub fn compute_aggregate_response<C: Ciphersuite>(
+    cs: &C,
+    participants_responses: &[Response<C>],
+) -> C::Scalar {
     participants_responses
         .iter()
-        .fold(Integer::from(0), |acc, pr| {
-            modular::add(acc, pr.clone(), &state.q)
+        .fold(cs.scalar_from_u32(0), |acc, response| {
+            cs.scalar_add(&acc, &response.value)
+        })
+}
+
+/// A finished Schnorr signature: the group commitment `r` together with the
+/// aggregate response `z`. Unlike the per-participant commitment list, this is
+/// all an outside verifier needs, and it is generic over the ciphersuite so the
+/// same type serves the integer group, Ed25519, Ristretto255, etc.
+pub struct Signature<C: Ciphersuite> {
+    pub r: C::Element,
+    pub z: C::Scalar,
+}
+
+impl<C: Ciphersuite> Signature<C> {
+    pub fn new(r: C::Element, z: C::Scalar) -> Self {
+        Self { r, z }
+    }
+
+    /// Build the signature from the group commitment and the aggregate
+    /// response produced by [`compute_aggregate_response`].
+    pub fn from_aggregate(group_commitment: C::Element, aggregate_response: C::Scalar) -> Self {
+        Self::new(group_commitment, aggregate_response)
+    }
+
+    /// Canonical wire encoding: `r` then `z`, each serialized with the
+    /// ciphersuite encoding under a `u32` length prefix.
+    pub fn to_bytes(&self, cs: &C) -> Vec<u8> {
+        let mut out = Vec::new();
+        push_field(&mut out, &cs.serialize_element(&self.r));
+        push_field(&mut out, &cs.serialize_scalar(&self.z));
+        out
+    }
+
+    /// Reconstruct a signature from [`Signature::to_bytes`]; returns `None` on
+    /// truncated or malformed framing.
+    pub fn from_bytes(cs: &C, bytes: &[u8]) -> Option<Self> {
+        let mut offset = 0;
+        let r = cs.deserialize_element(read_field(bytes, &mut offset)?);
+        let z = cs.deserialize_scalar(read_field(bytes, &mut offset)?);
+        Some(Self::new(r, z))
+    }
+}
+
+/// Sample a non-zero blinding scalar (a zero blind would drop a term from the
+/// combined check).
+fn random_blinding_scalar<C: Ciphersuite>(cs: &C) -> C::Scalar {
+    let zero = cs.scalar_from_u32(0);
+    let a = cs.random_scalar();
+    if a == zero {
+        cs.scalar_from_u32(1)
+    } else {
+        a
+    }
+}
+
+/// Verify many signatures at once with a single combined multi-exponentiation.
+///
+/// Each item is blinded by a fresh random scalar `a_k` and its challenge
+/// `c_k = H(r_k :: Y_k :: m_k)` recomputed, then the batch accepts iff
+/// `g^{Σ a_k z_k} == ∏_k (r_k^{a_k} * Y_k^{a_k c_k})`. A single invalid
+/// signature breaks the identity with overwhelming probability. The returned
+/// vector is parallel to `items`: on a passing batch every entry is `true`;
+/// when the combined check fails we fall back to verifying each item
+/// individually so the caller can pinpoint which signature(s) are bad.
+pub fn batch_verify<C: Ciphersuite>(
+    cs: &C,
+    items: &[(C::Element, String, Signature<C>)],
+) -> Vec<bool> {
+    let mut response_sum = cs.scalar_from_u32(0);
+    let mut commitment_product = cs.identity();
+    for (group_public_key, message, signature) in items {
+        let a = random_blinding_scalar(cs);
+        let challenge = cs.challenge(&signature.r, group_public_key, message);
+        response_sum = cs.scalar_add(&response_sum, &cs.scalar_mul(&a, &signature.z));
+        let term = cs.mul(
+            &cs.pow(&signature.r, &a),
+            &cs.pow(group_public_key, &cs.scalar_mul(&a, &challenge)),
+        );
+        commitment_product = cs.mul(&commitment_product, &term);
+    }
+    if cs.pow(&cs.generator(), &response_sum) == commitment_product {
+        return vec![true; items.len()];
+    }
+    items
+        .iter()
+        .map(|(group_public_key, message, signature)| {
+            verify_signature(cs, group_public_key, message, signature)
         })
+        .collect()
+}
+
+/// Verify a finished signature against the group public key without access to
+/// the per-participant `di`/`ei` commitments: recompute the challenge
+/// `c = H(r :: Y :: message)` and check that `g^z == r * Y^c`.
+pub fn verify_signature<C: Ciphersuite>(
+    cs: &C,
+    group_public_key: &C::Element,
+    message: &str,
+    signature: &Signature<C>,
+) -> bool {
+    let challenge = cs.challenge(&signature.r, group_public_key, message);
+    let gz = cs.pow(&cs.generator(), &signature.z);
+    let expected = cs.mul(&signature.r, &cs.pow(group_public_key, &challenge));
+    gz == expected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small_state() -> FrostState {
+        // A tiny safe-prime pair (p = 2q + 1 = 23) is enough to exercise the
+        // scalar arithmetic; `2` has order `q = 11` modulo `23`.
+        FrostState::new(Integer::from(23), Integer::from(11), Integer::from(2))
+    }
+
+    /// A realistically-sized safe-prime pair, large enough that scalar sums
+    /// routinely wrap around `q` — unlike the tiny fixtures above, this is
+    /// what catches a group/scalar modulus mix-up. `25` has order
+    /// `q = 1_000_151` modulo the safe prime `p = 2_000_303`.
+    fn large_state() -> FrostState {
+        FrostState::new(
+            Integer::from(2_000_303),
+            Integer::from(1_000_151),
+            Integer::from(25),
+        )
+    }
+
+    #[test]
+    fn lagrange_coefficient_over_explicit_signing_set() {
+        let state = small_state();
+        let signing_set: Vec<Integer> = [1, 2, 3].into_iter().map(Integer::from).collect();
+        // λ_1 = ∏_{j∈{2,3}} x_j / (x_j - 1) = (2/1) * (3/2) = 3 (mod 11).
+        let lambda = lagrange_coefficient(&state, &Integer::from(1), &signing_set);
+        assert_eq!(lambda, Integer::from(3));
+        // A coefficient restricted to the singleton set is the empty product, 1.
+        let singleton = lagrange_coefficient(&state, &Integer::from(1), &[Integer::from(1)]);
+        assert_eq!(singleton, Integer::from(1));
+    }
+
+    #[test]
+    fn signature_byte_roundtrip() {
+        let state = large_state();
+        let signature = Signature::<FrostState>::new(Integer::from(42), Integer::from(7));
+        let bytes = signature.to_bytes(&state);
+        let decoded = Signature::<FrostState>::from_bytes(&state, &bytes).unwrap();
+        assert_eq!(decoded.r, signature.r);
+        assert_eq!(decoded.z, signature.z);
+        // Truncated framing is rejected rather than panicking.
+        assert!(Signature::<FrostState>::from_bytes(&state, &bytes[..bytes.len() - 1]).is_none());
+    }
+
+    #[test]
+    fn batch_verify_returns_per_item_results() {
+        let state = large_state();
+        // An empty batch trivially satisfies the combined check.
+        assert_eq!(batch_verify(&state, &[]), Vec::<bool>::new());
+        // A bogus signature forces the individual-verification fallback, whose
+        // result vector is parallel to the input.
+        let items = vec![(
+            Integer::from(5),
+            "m".to_string(),
+            Signature::<FrostState>::new(Integer::from(11), Integer::from(13)),
+        )];
+        let results = batch_verify(&state, &items);
+        assert_eq!(results.len(), items.len());
+    }
+
+    #[test]
+    fn public_commitment_byte_roundtrip() {
+        let state = large_state();
+        let commitment = PublicCommitment::<FrostState>::new(
+            Integer::from(1),
+            Integer::from(8),
+            Integer::from(4),
+            Integer::from(32),
+        );
+        let bytes = commitment.to_bytes(&state);
+        let decoded = PublicCommitment::<FrostState>::from_bytes(&state, &bytes).unwrap();
+        assert_eq!(decoded.participant_id, commitment.participant_id);
+        assert_eq!(decoded.di, commitment.di);
+        assert_eq!(decoded.ei, commitment.ei);
+        assert_eq!(decoded.public_share, commitment.public_share);
+    }
+
+    #[test]
+    fn binding_value_binds_the_whole_commitment_set() {
+        let state = large_state();
+        let set = vec![
+            PublicCommitment::<FrostState>::new(
+                Integer::from(1),
+                Integer::from(8),
+                Integer::from(4),
+                Integer::from(32),
+            ),
+            PublicCommitment::<FrostState>::new(
+                Integer::from(2),
+                Integer::from(16),
+                Integer::from(64),
+                Integer::from(2),
+            ),
+        ];
+        // Deterministic in the full set, and the two participants get distinct
+        // binding factors from the same commitment list.
+        let rho1 = compute_binding_value(&state, &Integer::from(1), &set, "msg");
+        let rho1_again = compute_binding_value(&state, &Integer::from(1), &set, "msg");
+        let rho2 = compute_binding_value(&state, &Integer::from(2), &set, "msg");
+        assert_eq!(rho1, rho1_again);
+        assert_ne!(rho1, rho2);
+        // Reordering the commitment set changes the binding factor (it binds the
+        // entire ordered list, per the RFC).
+        let reordered: Vec<_> = set.into_iter().rev().collect();
+        let rho1_reordered = compute_binding_value(&state, &Integer::from(1), &reordered, "msg");
+        assert_ne!(rho1, rho1_reordered);
+    }
+
+    #[test]
+    fn response_byte_roundtrip() {
+        let state = large_state();
+        let response = Response::<FrostState>::new(Integer::from(1), Integer::from(42));
+        let bytes = response.to_bytes(&state);
+        let decoded = Response::<FrostState>::from_bytes(&state, &bytes).unwrap();
+        assert_eq!(decoded.participant_id, response.participant_id);
+        assert_eq!(decoded.value, response.value);
+        // Truncated framing is rejected rather than panicking.
+        assert!(Response::<FrostState>::from_bytes(&state, &bytes[..bytes.len() - 1]).is_none());
+    }
+
+    /// A group with a single-modulus (no safe-prime `p`) Schnorr group fails
+    /// `g^z == r * Y^c` for essentially any realistically-sized nonce/key,
+    /// because `Z_q^*` has order `q - 1`, not `q`. Signing with
+    /// `random_scalar()`-sized values (rather than the tiny hand-picked
+    /// fixtures above) is what actually exercises that wraparound.
+    #[test]
+    fn single_signer_verify_signature_accepts_random_scalars() {
+        let state = large_state();
+        for _ in 0..20 {
+            let private_key = state.random_scalar();
+            let nonce = state.random_scalar();
+            let public_key = state.pow(&state.generator(), &private_key);
+            let r = state.pow(&state.generator(), &nonce);
+            let challenge = state.challenge(&r, &public_key, "msg");
+            let z = state.scalar_add(&nonce, &state.scalar_mul(&private_key, &challenge));
+            let signature = Signature::<FrostState>::new(r, z);
+            assert!(verify_signature(&state, &public_key, "msg", &signature));
+        }
+    }
+
+    /// The threshold analogue of the test above: split a random group secret
+    /// across a real 2-of-2 signing set with a degree-1 polynomial, run an
+    /// actual signing round with `random_scalar()`-sized nonces, and check
+    /// that `verify_participants` accepts the aggregate.
+    #[test]
+    fn verify_participants_accepts_a_real_threshold_round_with_random_scalars() {
+        let state = large_state();
+        let message = "threshold msg";
+
+        let a0 = state.random_scalar();
+        let a1 = state.random_scalar();
+        let share_of = |id: u32| -> Integer {
+            state.scalar_add(&a0, &state.scalar_mul(&a1, &state.scalar_from_u32(id)))
+        };
+        let group_public_key = state.pow(&state.generator(), &a0);
+        let ids = [1u32, 2u32];
+        let signing_set: Vec<Integer> = ids.iter().map(|id| state.scalar_from_u32(*id)).collect();
+        let nonces: Vec<(Integer, Integer)> = ids
+            .iter()
+            .map(|_| (state.random_scalar(), state.random_scalar()))
+            .collect();
+        let commitments: Vec<PublicCommitment<FrostState>> = ids
+            .iter()
+            .zip(&signing_set)
+            .zip(&nonces)
+            .map(|((id, participant_id), (d, e))| {
+                PublicCommitment::new(
+                    participant_id.clone(),
+                    state.pow(&state.generator(), d),
+                    state.pow(&state.generator(), e),
+                    state.pow(&state.generator(), &share_of(*id)),
+                )
+            })
+            .collect();
+
+        let (_, challenge) = compute_group_commitment_and_challenge(
+            &state,
+            &commitments,
+            message,
+            group_public_key,
+        );
+        let responses: Vec<Response<FrostState>> = ids
+            .iter()
+            .zip(&commitments)
+            .zip(&nonces)
+            .map(|((id, commitment), nonces)| {
+                compute_own_response(
+                    &state,
+                    commitment,
+                    &commitments,
+                    &share_of(*id),
+                    nonces,
+                    &signing_set,
+                    &challenge,
+                    message,
+                )
+            })
+            .collect();
+        let aggregate_response = compute_aggregate_response(&state, &responses);
+
+        assert!(verify_participants(
+            &state,
+            &commitments,
+            message,
+            &aggregate_response,
+            &challenge,
+            &signing_set,
+        ));
+    }
+
+    /// The 2-of-2 round above never actually exercises a *strict* subset: the
+    /// whole point of arbitrary-subset Lagrange interpolation is a signing set
+    /// smaller than — and not the default `0..n` prefix of — the full
+    /// keyholder set. Here there are three keyholders with non-sequential ids
+    /// `2, 3, 5`, but only `3` and `5` sign; `2`'s share is never touched.
+    #[test]
+    fn verify_participants_accepts_a_strict_subset_of_a_larger_keyholder_set() {
+        let state = large_state();
+        let message = "threshold msg";
+
+        let a0 = state.random_scalar();
+        let a1 = state.random_scalar();
+        let share_of = |id: u32| -> Integer {
+            state.scalar_add(&a0, &state.scalar_mul(&a1, &state.scalar_from_u32(id)))
+        };
+        let group_public_key = state.pow(&state.generator(), &a0);
+
+        // Three keyholders in total; only `3` and `5` sign.
+        let all_keyholder_ids = [2u32, 3u32, 5u32];
+        let signing_ids = [3u32, 5u32];
+        assert!(signing_ids.len() < all_keyholder_ids.len());
+        assert!(all_keyholder_ids.contains(&2) && !signing_ids.contains(&2));
+
+        let signing_set: Vec<Integer> = signing_ids
+            .iter()
+            .map(|id| state.scalar_from_u32(*id))
+            .collect();
+        let nonces: Vec<(Integer, Integer)> = signing_ids
+            .iter()
+            .map(|_| (state.random_scalar(), state.random_scalar()))
+            .collect();
+        let commitments: Vec<PublicCommitment<FrostState>> = signing_ids
+            .iter()
+            .zip(&signing_set)
+            .zip(&nonces)
+            .map(|((id, participant_id), (d, e))| {
+                PublicCommitment::new(
+                    participant_id.clone(),
+                    state.pow(&state.generator(), d),
+                    state.pow(&state.generator(), e),
+                    state.pow(&state.generator(), &share_of(*id)),
+                )
+            })
+            .collect();
+
+        let (_, challenge) = compute_group_commitment_and_challenge(
+            &state,
+            &commitments,
+            message,
+            group_public_key,
+        );
+        let responses: Vec<Response<FrostState>> = signing_ids
+            .iter()
+            .zip(&commitments)
+            .zip(&nonces)
+            .map(|((id, commitment), nonces)| {
+                compute_own_response(
+                    &state,
+                    commitment,
+                    &commitments,
+                    &share_of(*id),
+                    nonces,
+                    &signing_set,
+                    &challenge,
+                    message,
+                )
+            })
+            .collect();
+        let aggregate_response = compute_aggregate_response(&state, &responses);
+
+        assert!(verify_participants(
+            &state,
+            &commitments,
+            message,
+            &aggregate_response,
+            &challenge,
+            &signing_set,
+        ));
+    }
 }